@@ -6,12 +6,19 @@
 //! - `store_tick` - save current tick to bb
 //! - `check_eq` - compare a value in the cell with the given expected value
 //! - `test_bool` - compare a value in the cell with the true
+//! - `all_true` - check if all values in the given cells are true
+//! - `any_true` - check if at least one value in the given cells is true
 //! - `generate_data` - a simple action that can generate and then update data in the given cell in bb.
+//! - `checkpoint` - dump the bb to the file every given amount of ticks
+//! - `decide` - gather the given cells into the decision of the run
+//! - `transition` - update the state cell according to the transition table of a state machine
 
 use crate::runtime::action::{Impl, Tick};
 use crate::runtime::args::{RtArgs, RtValue};
 use crate::runtime::context::TreeContextRef;
 use crate::runtime::{RuntimeError, TickResult};
+use std::collections::HashMap;
+use std::path::PathBuf;
 
 /// Check if the key is locked in BlackBoard
 pub struct Locked;
@@ -129,6 +136,74 @@ impl Impl for TestBool {
     }
 }
 
+/// Check if all values in the given cells are true
+/// The non bool values are coerced to bool (see `RtValue::as_truthy`)
+/// or lead to the error if the argument `strict` is true.
+pub struct AllTrue;
+
+impl Impl for AllTrue {
+    fn tick(&self, args: RtArgs, ctx: TreeContextRef) -> Tick {
+        let values = read_bools(args, ctx)?;
+        if values.iter().all(|v| *v) {
+            Ok(TickResult::success())
+        } else {
+            Ok(TickResult::failure("not all values are true".to_string()))
+        }
+    }
+}
+
+/// Check if at least one value in the given cells is true
+/// The non bool values are coerced to bool (see `RtValue::as_truthy`)
+/// or lead to the error if the argument `strict` is true.
+pub struct AnyTrue;
+
+impl Impl for AnyTrue {
+    fn tick(&self, args: RtArgs, ctx: TreeContextRef) -> Tick {
+        let values = read_bools(args, ctx)?;
+        if values.iter().any(|v| *v) {
+            Ok(TickResult::success())
+        } else {
+            Ok(TickResult::failure("no value is true".to_string()))
+        }
+    }
+}
+
+fn read_bools(args: RtArgs, ctx: TreeContextRef) -> Result<Vec<bool>, RuntimeError> {
+    let keys = args
+        .find_or_ith("keys".to_string(), 0)
+        .ok_or(RuntimeError::fail("the keys are expected".to_string()))?
+        .cast(ctx.clone())
+        .map_vec(RtValue::as_string)?
+        .ok_or(RuntimeError::fail("the keys are expected to be an array".to_string()))?;
+
+    let strict = match args.find_or_ith("strict".to_string(), 1) {
+        None => false,
+        Some(v) => v.cast(ctx.clone()).bool()?.ok_or(RuntimeError::fail(
+            "the strict is expected to be a bool".to_string(),
+        ))?,
+    };
+
+    let bb = ctx.bb();
+    let bb = bb.lock()?;
+    let mut values = vec![];
+    for key in keys {
+        let key = key.ok_or(RuntimeError::fail("the keys are expected to be strings".to_string()))?;
+        let value = bb
+            .get(key.clone())?
+            .cloned()
+            .ok_or(RuntimeError::fail(format!("the key {key} is absent")))?;
+        let value = if strict {
+            value.as_bool()
+        } else {
+            value.as_truthy()
+        };
+        values.push(value.ok_or(RuntimeError::fail(format!(
+            "the value in the key {key} can not be taken as a bool"
+        )))?);
+    }
+    Ok(values)
+}
+
 /// A simple action that can generate and then update data in the given cell in bb.
 /// Encompasses a function that accepts a current value of the cell and then place the updated one.
 ///
@@ -232,6 +307,151 @@ impl Impl for StoreData {
     }
 }
 
+/// Dumps the bb to the file in json format every `interval_ticks` ticks.
+/// The snapshot can be loaded afterwards with `ForesterBuilder::bb_load` to resume the run.
+/// The relative path is resolved against the root folder of the project as `bb_load` does.
+///
+/// ## Note:
+/// The interval is counted from the last dump, that is kept in the cell `checkpoint:<path>`,
+/// therefore the node dumps on the first visit and does not depend on being ticked on every tick.
+/// The interval 0 means the snapshot is dumped on every visit.
+pub struct Checkpoint;
+
+impl Impl for Checkpoint {
+    fn tick(&self, args: RtArgs, ctx: TreeContextRef) -> Tick {
+        let path = args
+            .find_or_ith("path".to_string(), 0)
+            .ok_or(RuntimeError::fail("the path is expected".to_string()))?
+            .cast(ctx.clone())
+            .str()?
+            .ok_or(RuntimeError::fail("the path is expected to be a string".to_string()))?;
+
+        let interval = args
+            .find_or_ith("interval_ticks".to_string(), 1)
+            .ok_or(RuntimeError::fail("the interval_ticks is expected".to_string()))?
+            .cast(ctx.clone())
+            .int()?
+            .and_then(|v| usize::try_from(v).ok())
+            .ok_or(RuntimeError::fail(
+                "the interval_ticks is expected to be a non negative int".to_string(),
+            ))?;
+
+        let curr_tick = ctx.current_tick();
+        let last_key = format!("checkpoint:{path}");
+        let bb = ctx.bb();
+        let mut bb = bb.lock()?;
+        let last = bb
+            .get(last_key.clone())?
+            .and_then(|v| v.clone().as_int())
+            .and_then(|v| usize::try_from(v).ok());
+
+        // the tick of the last dump can be ahead of the current one if the snapshot is loaded
+        let due = last
+            .and_then(|last| curr_tick.checked_sub(last))
+            .is_none_or(|passed| passed >= interval);
+        if due {
+            bb.put(last_key, RtValue::int(curr_tick as i64))?;
+            bb.dump(ctx.path(&PathBuf::from(path))?)?;
+        }
+        Ok(TickResult::success())
+    }
+}
+
+/// Gathers the values of the given cells into one object
+/// marking that the current run has made a decision.
+/// The decision can be read afterwards with `Forester::decision`.
+/// If the `target` is given, the object is also placed to the cell with this key.
+/// The cell alone does not mark the decision since any action can write to it.
+pub struct Decide;
+
+impl Impl for Decide {
+    fn tick(&self, args: RtArgs, ctx: TreeContextRef) -> Tick {
+        let keys = args
+            .find_or_ith("keys".to_string(), 0)
+            .ok_or(RuntimeError::fail("the keys are expected".to_string()))?
+            .cast(ctx.clone())
+            .map_vec(RtValue::as_string)?
+            .ok_or(RuntimeError::fail("the keys are expected to be an array".to_string()))?;
+
+        let target = match args.find_or_ith("target".to_string(), 1) {
+            None => None,
+            Some(v) => Some(
+                v.cast(ctx.clone())
+                    .str()?
+                    .ok_or(RuntimeError::fail("the target is expected to be a string".to_string()))?,
+            ),
+        };
+
+        let bb = ctx.bb();
+        let mut bb = bb.lock()?;
+        let mut decision = HashMap::new();
+        for key in keys {
+            let key = key.ok_or(RuntimeError::fail("the keys are expected to be strings".to_string()))?;
+            let value = bb
+                .get(key.clone())?
+                .cloned()
+                .ok_or(RuntimeError::fail(format!("the key {key} is absent")))?;
+            decision.insert(key, value);
+        }
+        let decision = RtValue::Object(decision);
+        if let Some(target) = target {
+            bb.put(target, decision.clone())?;
+        }
+        ctx.decide(decision)?;
+        Ok(TickResult::success())
+    }
+}
+
+/// Performs a transition of a state machine.
+/// Takes the current state and the event from the given cells
+/// and looks up the next state in the table by the key `state:event`.
+/// If the transition is defined, the next state is placed to the state cell.
+/// Otherwise, the action returns failure leaving the state unchanged.
+pub struct Transition;
+
+impl Impl for Transition {
+    fn tick(&self, args: RtArgs, ctx: TreeContextRef) -> Tick {
+        let key = |name: &str, idx: usize| {
+            args.find_or_ith(name.to_string(), idx)
+                .ok_or(RuntimeError::fail(format!("the {name} is expected")))?
+                .cast(ctx.clone())
+                .str()?
+                .ok_or(RuntimeError::fail(format!("the {name} is expected to be a string")))
+        };
+        let state_key = key("state", 0)?;
+        let event_key = key("event", 1)?;
+
+        let table = args
+            .find_or_ith("table".to_string(), 2)
+            .ok_or(RuntimeError::fail("the table is expected".to_string()))?
+            .cast(ctx.clone())
+            .map_obj(|(k, v)| (k, v))?
+            .ok_or(RuntimeError::fail("the table is expected to be an object".to_string()))?;
+
+        let bb = ctx.bb();
+        let mut bb = bb.lock()?;
+        let value = |key: &String| -> Result<String, RuntimeError> {
+            bb.get(key.clone())?
+                .cloned()
+                .ok_or(RuntimeError::fail(format!("the key {key} is absent")))?
+                .as_string()
+                .ok_or(RuntimeError::fail(format!("the key {key} is expected to be a string")))
+        };
+        let state = value(&state_key)?;
+        let event = value(&event_key)?;
+
+        match table.get(&format!("{state}:{event}")) {
+            Some(next) => {
+                bb.put(state_key, next.clone())?;
+                Ok(TickResult::success())
+            }
+            None => Ok(TickResult::failure(format!(
+                "the transition from {state} on {event} is not defined"
+            ))),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::runtime::action::builtin::data::LockUnlockBBKey;
@@ -150,6 +150,25 @@ impl RtValue {
             _ => None,
         }
     }
+    /// coerces the value to bool:
+    /// - bool is taken as is
+    /// - number is true if it is not zero
+    /// - string, array and object are true if they are not empty
+    /// - pointer and call can not be coerced
+    pub fn as_truthy(self) -> Option<bool> {
+        match self {
+            RtValue::Bool(v) => Some(v),
+            RtValue::Number(RtValueNumber::Int(v)) | RtValue::Number(RtValueNumber::Hex(v)) => {
+                Some(v != 0)
+            }
+            RtValue::Number(RtValueNumber::Binary(v)) => Some(v != 0),
+            RtValue::Number(RtValueNumber::Float(v)) => Some(v != 0.0),
+            RtValue::String(v) => Some(!v.is_empty()),
+            RtValue::Array(elems) => Some(!elems.is_empty()),
+            RtValue::Object(elems) => Some(!elems.is_empty()),
+            RtValue::Pointer(_) | RtValue::Call(_) => None,
+        }
+    }
     pub fn as_vec<Map, To>(self, map: Map) -> Option<Vec<To>>
         where
             Map: Fn(RtValue) -> To,
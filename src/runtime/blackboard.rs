@@ -6,6 +6,7 @@ use crate::runtime::blackboard::BBValue::{Locked, Taken, Unlocked};
 use crate::runtime::{RtOk, RtResult, RuntimeError};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::fmt::{Debug, Formatter};
 use std::fs;
 use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
@@ -41,6 +42,37 @@ pub enum BBValue {
 #[derive(Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct BlackBoard {
     storage: HashMap<BBKey, BBValue>,
+    #[serde(skip)]
+    validator: Option<KeyValidator>,
+}
+
+/// The validator of the keys that checks every key before writing it to the bb.
+/// The reading operations are not validated.
+#[derive(Clone)]
+pub struct KeyValidator(Arc<dyn Fn(&str) -> bool + Send + Sync>);
+
+impl KeyValidator {
+    pub fn new<F>(f: F) -> Self
+        where
+            F: Fn(&str) -> bool + Send + Sync + 'static,
+    {
+        KeyValidator(Arc::new(f))
+    }
+    pub fn validate(&self, key: &str) -> bool {
+        (self.0)(key)
+    }
+}
+
+impl Debug for KeyValidator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("KeyValidator")
+    }
+}
+
+impl PartialEq for KeyValidator {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
 }
 
 impl BlackBoard {
@@ -132,8 +164,13 @@ impl BlackBoard {
     /// Puts an value to a cell
     ///
     /// #Notes:
-    /// Error if it is locked
+    /// Error if it is locked or if the key does not pass the validator
     pub fn put(&mut self, key: BBKey, value: RtValue) -> RtOk {
+        if let Some(validator) = &self.validator {
+            if !validator.validate(key.as_str()) {
+                return Err(RuntimeError::bb(format!("the key {key} is not valid")));
+            }
+        }
         let curr = self.storage.get(&key);
         match curr {
             Some(Locked(_)) => Err(RuntimeError::bb(format!("the key {key} is locked"))),
@@ -148,8 +185,14 @@ impl BlackBoard {
         debug!(target:"bb", "create an empty bb");
         Self {
             storage: HashMap::from_iter(elems),
+            validator: None,
         }
     }
+
+    /// Sets the validator that checks every key before writing to the bb.
+    pub fn set_key_validator(&mut self, validator: KeyValidator) {
+        self.validator = Some(validator);
+    }
 }
 impl BlackBoard {
     /// Drops the snapshot to the file in json format.
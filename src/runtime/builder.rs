@@ -9,7 +9,7 @@ use crate::get_pb;
 use crate::runtime::action::builtin::remote::RemoteHttpAction;
 use crate::runtime::action::keeper::{ActionImpl, ActionKeeper};
 use crate::runtime::action::{Action, ActionName, Impl, ImplAsync, ImplRemote};
-use crate::runtime::blackboard::BlackBoard;
+use crate::runtime::blackboard::{BlackBoard, KeyValidator};
 use crate::runtime::builder::custom_builder::CustomForesterBuilder;
 use crate::runtime::builder::file_builder::FileForesterBuilder;
 use crate::runtime::builder::text_builder::TextForesterBuilder;
@@ -231,6 +231,16 @@ impl ForesterBuilder {
         self.cfb().bb_load(bb);
     }
 
+    /// A validator that checks every key before writing it to the bb.
+    /// If the key is not valid, the writing fails with `RuntimeError::BlackBoardError`.
+    /// The reading operations are not validated.
+    pub fn with_key_validator<F>(&mut self, validator: F)
+        where
+            F: Fn(&str) -> bool + Send + Sync + 'static,
+    {
+        self.cfb().key_validator(KeyValidator::new(validator));
+    }
+
     /// Mix the runtime async env(tokio env)
     /// By default, creates the default tokio Runtime multi thread
     pub fn rt_env(&mut self, env: RtEnv) {
@@ -257,6 +267,7 @@ impl ForesterBuilder {
             tr,
             env,
             bb_load,
+            key_validator,
             root,
             port
         ) = match self {
@@ -284,6 +295,7 @@ impl ForesterBuilder {
                     cfb.tracer,
                     cfb.env,
                     cfb.bb_load,
+                    cfb.key_validator,
                     root,
                     cfb.port,
                 )
@@ -308,6 +320,7 @@ impl ForesterBuilder {
                     cfb.tracer,
                     cfb.env,
                     cfb.bb_load,
+                    cfb.key_validator,
                     None,
                     cfb.port,
                 )
@@ -322,17 +335,22 @@ impl ForesterBuilder {
                     cfb.tracer,
                     cfb.env,
                     cfb.bb_load,
+                    cfb.key_validator,
                     None,
                     cfb.port,
                 )
             }
         };
 
-        let bb =
+        let mut bb =
             if let Some(bb_load_dump) = bb_load {
                 BlackBoard::load(&get_pb(&PathBuf::from(bb_load_dump), &root)?)?
             } else { BlackBoard::default() };
 
+        if let Some(validator) = key_validator {
+            bb.set_key_validator(validator);
+        }
+
         let mut env = if let Some(e) = env {
             e
         } else {
@@ -364,7 +382,7 @@ impl ForesterBuilder {
 
         let keeper = ActionKeeper::new_with(actions, action_names, default_action)?;
 
-        Forester::new(tree, bb, tracer, keeper, env, serv)
+        Forester::new(tree, bb, tracer, keeper, env, serv, root)
     }
 
     fn cfb(&mut self) -> &mut CommonForesterBuilder {
@@ -390,6 +408,7 @@ pub struct CommonForesterBuilder {
     env: Option<RtEnv>,
     tracer: Tracer,
     bb_load: Option<String>,
+    key_validator: Option<KeyValidator>,
     actions: HashMap<ActionName, Action>,
     daemons: Vec<DaemonTaskCfg>,
     port: ServerPort,
@@ -401,6 +420,7 @@ impl CommonForesterBuilder {
             env: None,
             tracer: Tracer::noop(),
             bb_load: None,
+            key_validator: None,
             actions: HashMap::new(),
             daemons: Vec::new(),
             port: ServerPort::None,
@@ -458,6 +478,11 @@ impl CommonForesterBuilder {
         self.bb_load = Some(bb);
     }
 
+    /// A validator that checks every key before writing it to the bb.
+    pub fn key_validator(&mut self, validator: KeyValidator) {
+        self.key_validator = Some(validator);
+    }
+
     /// Mix the runtime async env(tokio env)
     /// By default, creates the default tokio Runtime multi thread
     pub fn rt_env(&mut self, env: RtEnv) {
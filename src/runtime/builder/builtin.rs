@@ -1,4 +1,4 @@
-use crate::runtime::action::builtin::data::{CheckEq, LockUnlockBBKey, Locked, StoreData, StoreTick, TestBool, Less};
+use crate::runtime::action::builtin::data::{AllTrue, AnyTrue, CheckEq, Checkpoint, Decide, Transition, LockUnlockBBKey, Locked, StoreData, StoreTick, TestBool, Less};
 use crate::runtime::action::builtin::http::HttpGet;
 use crate::runtime::action::builtin::ReturnResult;
 use crate::runtime::action::{Action, ActionName};
@@ -31,7 +31,12 @@ fn action_impl(action: &ActionName) -> RtResult<Action> {
         "equal" => Ok(Action::sync(CheckEq)),
         "less" => Ok(Action::sync(Less)),
         "test" => Ok(Action::sync(TestBool)),
+        "all_true" => Ok(Action::sync(AllTrue)),
+        "any_true" => Ok(Action::sync(AnyTrue)),
         "store_tick" => Ok(Action::sync(StoreTick)),
+        "checkpoint" => Ok(Action::sync(Checkpoint)),
+        "decide" => Ok(Action::sync(Decide)),
+        "transition" => Ok(Action::sync(Transition)),
         "http_get" => Ok(Action::sync(HttpGet)),
         "http_get_async" => Ok(Action::a_sync(HttpGet)),
         "lock" => Ok(Action::sync(LockUnlockBBKey::Lock)),
@@ -87,9 +92,37 @@ impl less(lhs:num, rhs:num);
 // - Returns Fail(reason) if there is no cell in bbe with the given key.
 impl test(key:string);
 
+// Checks the values in the given cells:
+// - all_true returns Result::Success if all values are true
+// - any_true returns Result::Success if at least one value is true
+// - Returns Fail(reason) otherwise or if there is no cell in bb with the given key.
+// The non bool values are coerced (0, "", [] and {} are false) unless strict is true.
+// In the strict mode, the non bool values lead to Fail(reason).
+impl all_true(keys:array, strict:bool);
+impl any_true(keys:array, strict:bool);
+
 /// Store the current tick
 impl store_tick(name:string);
 
+/// Dumps the bb to the file in json format every interval_ticks ticks counting from the last dump.
+/// The first visit always dumps. The tick of the last dump is kept in the cell 'checkpoint:<path>'.
+/// The relative path is resolved against the root folder as the bb_load does.
+/// The interval 0 means every visit. Returns Result::Success
+impl checkpoint(path:string, interval_ticks:num);
+
+// Gathers the values of the given cells into one object that marks the decision of the run.
+// The decision can be read by the host with Forester::decision, that returns None if no decision is made.
+// If the target is given, the object is also placed to the cell with this key.
+// Returns Fail(reason) if there is no cell in bb with the given key.
+impl decide(keys:array, target:string);
+
+// Performs a transition of a state machine.
+// Looks up the next state in the table by the key 'state:event',
+// where state and event are the values in the cells with the given keys:
+// - Returns Result::Success and places the next state to the state cell if the transition is defined
+// - Returns Fail(reason) leaving the state unchanged otherwise
+impl transition(state:string, event:string, table:object);
+
 /// Performs http get request
 impl http_get(url:string, bb_key:string);
 
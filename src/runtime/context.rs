@@ -1,6 +1,7 @@
 use crate::runtime::action::Tick;
 use crate::runtime::args::{RtArgs, RtValue};
 use crate::runtime::blackboard::{BBRef, BlackBoard};
+use crate::get_pb;
 use crate::runtime::env::{RtEnv, RtEnvRef};
 use crate::runtime::forester::flow::REASON;
 use crate::runtime::rtree::rnode::RNodeId;
@@ -10,11 +11,14 @@ use crate::tracer::Event::NewState;
 use crate::tracer::{Event, Tracer};
 use std::collections::{HashMap, VecDeque};
 use std::fmt::{Display, Formatter};
+use std::path::PathBuf;
 use std::sync::Arc;
 use std::sync::Mutex;
 
 pub type Timestamp = usize;
 pub type TracerRef = Arc<Mutex<Tracer>>;
+/// The decision that the tree has made in the current run (see the action `decide`)
+pub type DecisionRef = Arc<Mutex<Option<RtValue>>>;
 
 /// The remote context ref for the remote actions.
 /// Since, the context is supposed to help to send
@@ -45,11 +49,16 @@ pub struct TreeContextRef {
     curr_ts: Timestamp,
     trimmer: TrimmingQueueRef,
     env: RtEnvRef,
+    root: Option<PathBuf>,
+    decision: DecisionRef,
 }
 
 impl TreeContextRef {
     pub fn from_ctx(ctx: &TreeContext, trimmer: Arc<Mutex<TrimmingQueue>>) -> Self {
-        TreeContextRef::new(ctx.bb.clone(), ctx.tracer.clone(), ctx.curr_ts, trimmer, ctx.rt_env.clone())
+        let mut ctx_ref = TreeContextRef::new(ctx.bb.clone(), ctx.tracer.clone(), ctx.curr_ts, trimmer, ctx.rt_env.clone());
+        ctx_ref.root = ctx.root.clone();
+        ctx_ref.decision = ctx.decision.clone();
+        ctx_ref
     }
     /// A pointer to tracer struct.
     pub fn tracer(&self) -> TracerRef {
@@ -74,6 +83,18 @@ impl TreeContextRef {
     pub fn current_tick(&self) -> Timestamp {
         self.curr_ts
     }
+    /// Resolves the relative path against the root folder of the project
+    /// in the same way as the `ForesterBuilder::bb_load` does.
+    /// The absolute path is returned as is.
+    pub fn path(&self, file: &PathBuf) -> RtResult<PathBuf> {
+        get_pb(file, &self.root)
+    }
+    /// Marks the current run as having produced the given decision.
+    /// The decision can be read afterwards with `Forester::decision`.
+    pub fn decide(&self, decision: RtValue) -> RtOk {
+        *self.decision.lock()? = Some(decision);
+        Ok(())
+    }
     pub fn new(
         bb: Arc<Mutex<BlackBoard>>,
         tracer: Arc<Mutex<Tracer>>,
@@ -86,7 +107,9 @@ impl TreeContextRef {
             tracer,
             curr_ts,
             trimmer,
-            env
+            env,
+            root: None,
+            decision: Default::default(),
         }
     }
 }
@@ -116,6 +139,12 @@ pub struct TreeContext {
 
     /// The runtime environment
     rt_env: RtEnvRef,
+
+    /// The root folder of the project
+    root: Option<PathBuf>,
+
+    /// The decision made in the current run
+    decision: DecisionRef,
 }
 
 impl TreeContext {
@@ -130,7 +159,14 @@ impl TreeContext {
     pub fn tracer(&mut self) -> Arc<Mutex<Tracer>> {
         self.tracer.clone()
     }
-    pub fn new(bb: BBRef, tracer: TracerRef, tick_limit: Timestamp, rt_env: RtEnvRef) -> Self {
+    pub fn new(
+        bb: BBRef,
+        tracer: TracerRef,
+        tick_limit: Timestamp,
+        rt_env: RtEnvRef,
+        root: Option<PathBuf>,
+        decision: DecisionRef,
+    ) -> Self {
         Self {
             bb,
             tracer,
@@ -140,6 +176,8 @@ impl TreeContext {
             curr_ts: 1,
             tick_limit,
             rt_env,
+            root,
+            decision,
         }
     }
 }
@@ -5,11 +5,11 @@ pub mod serv;
 
 use crate::runtime::action::keeper::ActionKeeper;
 use crate::runtime::action::{recover, Tick};
-use crate::runtime::args::RtArgs;
+use crate::runtime::args::{RtArgs, RtValue};
 use crate::runtime::blackboard::BlackBoard;
-use crate::runtime::context::{RNodeState, TreeContext, TreeContextRef};
+use crate::runtime::context::{DecisionRef, RNodeState, TreeContext, TreeContextRef};
 use crate::runtime::env::RtEnv;
-use crate::runtime::forester::flow::{FlowDecision, read_cursor, run_with, run_with_par};
+use crate::runtime::forester::flow::{FlowDecision, read_cursor, run_with, run_with_par, REASON};
 use crate::runtime::forester::serv::ServInfo;
 use crate::runtime::rtree::rnode::RNode;
 use crate::runtime::rtree::RuntimeTree;
@@ -19,6 +19,7 @@ use crate::runtime::trimmer::{RequestBody, TreeSnapshot, TrimRequest, TrimmingQu
 use crate::runtime::{trimmer, RtOk, RtResult, RuntimeError};
 use crate::tracer::{Event, Tracer};
 use log::debug;
+use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
 use tokio::sync::oneshot::Sender;
 use tokio::task::JoinHandle;
@@ -41,6 +42,8 @@ pub struct Forester {
     pub env: Arc<Mutex<RtEnv>>,
     pub trimmer: Arc<Mutex<TrimmingQueue>>,
     serv: Option<ServInfo>,
+    root: Option<PathBuf>,
+    decision: DecisionRef,
 }
 
 impl Forester {
@@ -51,6 +54,7 @@ impl Forester {
         keeper: ActionKeeper,
         env: Arc<Mutex<RtEnv>>,
         serv: Option<ServInfo>,
+        root: Option<PathBuf>,
     ) -> RtResult<Self> {
         let trimmer = Arc::new(Mutex::new(TrimmingQueue::default()));
         Ok(Self {
@@ -61,6 +65,8 @@ impl Forester {
             env,
             trimmer,
             serv,
+            root,
+            decision: Default::default(),
         })
     }
 
@@ -148,6 +154,8 @@ impl Forester {
     /// Traverse the tree either until the root transits into either Failure or Success
     /// or some Exception will be thrown or the limit on ticks is exceeded.
     pub fn run_until(&mut self, max_tick: Option<usize>) -> Tick {
+        // the decision is made anew in every run
+        *self.decision.lock()? = None;
         // The ctx has a call stack to manage the flow.
         // When the flow goes up it pops the current element and leaps to the parent.
         let mut ctx = TreeContext::new(
@@ -155,6 +163,8 @@ impl Forester {
             self.tracer.clone(),
             max_tick.unwrap_or_default(),
             self.env.clone(),
+            self.root.clone(),
+            self.decision.clone(),
         );
         ctx.push(self.tree.root)?;
         // starts from root and pops up the element when either it is finished
@@ -164,6 +174,25 @@ impl Forester {
             debug!(target:"loop", "node = {}, tick = {}", id,ctx.curr_ts());
             match self.tree.node(&id)? {
                 RNode::Flow(tpe, _n, args, children) => match ctx.state_in_ts(&id) {
+                    // the range switch picks the only child to run at the start
+                    // according to the value of the key, or fails if no child matches.
+                    RNodeState::Ready(tick_args) if tpe.is_switch() => {
+                        let len = children.len() as i64;
+                        let new_state =
+                            match flow::range_switch_cursor(
+                                args.clone(),
+                                len,
+                                TreeContextRef::from_ctx(&ctx, self.trimmer.clone()),
+                            )? {
+                                Some(cursor) => RNodeState::Running(run_with(tick_args, cursor, len)),
+                                None => RNodeState::Failure(
+                                    run_with(tick_args, 0, len)
+                                        .with(REASON, RtValue::str("no range matches the key".to_string())),
+                                ),
+                            };
+                        debug!(target:"flow[ready]", "tick:{}, {tpe}. Switch to the new_state:{}",ctx.curr_ts(),&new_state);
+                        ctx.new_state(id, new_state)?;
+                    }
                     // do nothing, since there are no children
                     RNodeState::Ready(tick_args) if children.is_empty() => {
                         debug!(target:"flow[ready]", "tick:{}, {tpe}. The children are empty. Go to the fin state", ctx.curr_ts());
@@ -352,6 +381,12 @@ impl Forester {
         ctx.root_state(self.tree.root)
     }
 
+    /// The decision that the tree has made in the last run using the action `decide`.
+    /// Returns None if the tree has not made a decision.
+    pub fn decision(&self) -> Option<RtValue> {
+        self.decision.lock().ok().and_then(|d| d.clone())
+    }
+
     /// stops the http server
     pub fn stop_http(&mut self) {
         if let Some(serv) = self.serv.take() {
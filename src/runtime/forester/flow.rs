@@ -1,5 +1,5 @@
-use crate::runtime::args::{RtArgs, RtValue};
-use crate::runtime::context::{RNodeState, TreeContext};
+use crate::runtime::args::{RtArgs, RtValue, RtValueNumber};
+use crate::runtime::context::{RNodeState, TreeContext, TreeContextRef};
 use crate::runtime::rtree::rnode::FlowType;
 use crate::runtime::{RtResult, RuntimeError, TickResult};
 use std::cmp::max;
@@ -17,6 +17,15 @@ pub const P_CURSOR: &str = "prev_cursor";
 // reason for the failure
 pub const REASON: &str = "reason";
 
+// the value that is compared with the ranges in the range switch
+pub const KEY: &str = "key";
+// the list of ranges for the range switch
+pub const RANGES: &str = "ranges";
+// the exclusive upper bound of the range
+pub const LT: &str = "lt";
+// the inclusive lower bound of the range
+pub const GTE: &str = "gte";
+
 // the list of children and states, where
 // 0 is ready,
 // 1 is running,
@@ -75,6 +84,86 @@ pub(crate) fn read_cursor(tick_args: RtArgs) -> RtResult<i64> {
     }
 }
 
+// the range switch picks the child whose range contains the value of the key.
+// The ranges are given as an array of objects with the optional bounds `lt` and `gte`
+// and go in the same order as the children, therefore the first match wins if the ranges overlap.
+// The amount of ranges should be either equal to the amount of children or one less,
+// in the latter case the last child is considered as a default one.
+// Returns None if no range matches the value and there is no default child.
+pub fn range_switch_cursor(args: RtArgs, len: i64, ctx: TreeContextRef) -> RtResult<Option<i64>> {
+    let key = args
+        .find_or_ith(KEY.to_string(), 0)
+        .ok_or(RuntimeError::WrongArgument("the range_switch expects the key".to_string()))?
+        .cast(ctx.clone())
+        .with_ptr()?;
+    let value = as_number(&key).ok_or(RuntimeError::WrongArgument(format!(
+        "the key of the range_switch should be a number, but it is {key}"
+    )))?;
+
+    let ranges = args
+        .find_or_ith(RANGES.to_string(), 1)
+        .ok_or(RuntimeError::WrongArgument("the range_switch expects the ranges".to_string()))?
+        .cast(ctx)
+        .map_vec(|v| v)?
+        .ok_or(RuntimeError::WrongArgument(
+            "the range_switch expects the ranges as an array".to_string(),
+        ))?;
+
+    let default = ranges.len() as i64;
+    if default != len && default != len - 1 {
+        return Err(RuntimeError::WrongArgument(format!(
+            "the range_switch has {len} children, therefore it expects {len} ranges or {} with the default child, but got {default}",
+            len - 1
+        )));
+    }
+
+    // all ranges are validated upfront, so a typo in a bound can not hijack the switch
+    let mut bounds = vec![];
+    for range in ranges.iter() {
+        let elems = range.clone().as_map(|(k, v)| (k, v)).ok_or(RuntimeError::WrongArgument(
+            format!("the range {range} should be an object"),
+        ))?;
+        if let Some(unknown) = elems.keys().find(|k| k.as_str() != LT && k.as_str() != GTE) {
+            return Err(RuntimeError::WrongArgument(format!(
+                "the range {range} has the unknown bound {unknown}, only {LT} and {GTE} are expected"
+            )));
+        }
+        let bound = |name: &str| -> RtResult<Option<f64>> {
+            match elems.get(name) {
+                None => Ok(None),
+                Some(b) => as_number(b).map(Some).ok_or(RuntimeError::WrongArgument(format!(
+                    "the bound {name} of the range {range} should be a number"
+                ))),
+            }
+        };
+        bounds.push((bound(LT)?, bound(GTE)?));
+    }
+
+    for (idx, (lt, gte)) in bounds.into_iter().enumerate() {
+        let below = lt.map(|lt| value < lt).unwrap_or(true);
+        let above = gte.map(|gte| value >= gte).unwrap_or(true);
+        if below && above {
+            return Ok(Some(idx as i64));
+        }
+    }
+
+    if default < len {
+        Ok(Some(default))
+    } else {
+        Ok(None)
+    }
+}
+
+fn as_number(v: &RtValue) -> Option<f64> {
+    match v {
+        RtValue::Number(RtValueNumber::Int(i)) => Some(*i as f64),
+        RtValue::Number(RtValueNumber::Float(f)) => Some(*f),
+        RtValue::Number(RtValueNumber::Hex(h)) => Some(*h as f64),
+        RtValue::Number(RtValueNumber::Binary(b)) => Some(*b as f64),
+        _ => None,
+    }
+}
+
 /// Shortest version of TickResult, containing only finished statuses.
 pub enum TickResultFin {
     Failure(String),
@@ -215,6 +304,17 @@ pub fn finalize(
                 }
             }
         }
+        FlowType::RangeSwitch => {
+            let cursor = read_cursor(tick_args.clone())?;
+            let len = read_len_or_zero(tick_args.clone());
+
+            match res {
+                TickResultFin::Failure(v) => Ok(Stay(RNodeState::Failure(
+                    run_with(tick_args, cursor, len).with(REASON, RtValue::str(v)),
+                ))),
+                TickResultFin::Success => Ok(Stay(RNodeState::Success(run_with(tick_args, cursor, len)))),
+            }
+        }
 
         _ => Err(RuntimeError::UnImplementedAction("flow".to_string())),
     }
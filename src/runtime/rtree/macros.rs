@@ -375,6 +375,17 @@ macro_rules! flow {
 
         RtNodeBuilder::flow(FlowType::RFallback, $name, $args, elems)
     }};
+    (range_switch $name:expr, $args:expr; $($children:expr),+ ) => {{
+        let mut elems = Vec::new();
+        $( elems.push($children.into()) ; )+
+
+        RtNodeBuilder::flow(FlowType::RangeSwitch, $name, $args, elems)
+    }};
+    (range_switch $name:expr, $args:expr, $children:expr) => {{
+        let elems = $children.into_iter().map(|v|v.into()).collect();
+
+        RtNodeBuilder::flow(FlowType::RangeSwitch, $name, $args, elems)
+    }};
 }
 
 /// Creates RtNodeBuilder::decorator(..)
@@ -33,6 +33,7 @@ pub enum FlowType {
     RSequence,
     Fallback,
     RFallback,
+    RangeSwitch,
 }
 
 impl FlowType {
@@ -42,6 +43,9 @@ impl FlowType {
     pub fn is_par(&self) -> bool {
         matches!(self, FlowType::Parallel)
     }
+    pub fn is_switch(&self) -> bool {
+        matches!(self, FlowType::RangeSwitch)
+    }
 }
 
 impl TryFrom<TreeType> for DecoratorType {
@@ -73,6 +77,7 @@ impl TryFrom<TreeType> for FlowType {
             TreeType::RSequence => Ok(FlowType::RSequence),
             TreeType::Fallback => Ok(FlowType::Fallback),
             TreeType::RFallback => Ok(FlowType::RFallback),
+            TreeType::RangeSwitch => Ok(FlowType::RangeSwitch),
             e => Err(cerr(format!("unexpected type {e} for flow"))),
         }
     }
@@ -1,6 +1,13 @@
-use crate::runtime::args::RtValue;
-use crate::runtime::TickResult;
-use crate::tests::fb;
+use crate::runtime::args::{RtArgs, RtValue};
+use crate::runtime::blackboard::BlackBoard;
+use crate::runtime::builder::ForesterBuilder;
+use crate::runtime::action::{Impl, Tick};
+use crate::runtime::context::TreeContextRef;
+use crate::runtime::{RuntimeError, TickResult};
+use crate::tests::{fb, test_folder};
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
 
 #[test]
 fn builtin_actions() {
@@ -42,3 +49,242 @@ fn mix_test() {
     let mut f = fb.build().unwrap();
     assert_eq!(f.run(), Ok(TickResult::success()));
 }
+
+fn checkpoint_tick(path: &PathBuf) -> Option<RtValue> {
+    BlackBoard::load(path)
+        .unwrap()
+        .get("tick".to_string())
+        .unwrap()
+        .cloned()
+}
+
+#[test]
+fn checkpoint() {
+    let path = test_folder("actions/checkpoint/checkpoint.json");
+
+    // the ticks 1, 2 and 3 are performed, thus the tick 2 is skipped by the interval
+    let mut f = fb("actions/checkpoint").build().unwrap();
+    assert!(matches!(f.run_until(Some(4)), Err(RuntimeError::Stopped(_))));
+    assert_eq!(checkpoint_tick(&path), Some(RtValue::int(3)));
+
+    let mut f = fb("actions/checkpoint").build().unwrap();
+    assert_eq!(f.run(), Ok(TickResult::success()));
+    assert_eq!(checkpoint_tick(&path), Some(RtValue::int(5)));
+
+    let mut fb = fb("actions/checkpoint");
+    fb.bb_load("checkpoint.json".to_string());
+    let f = fb.build().unwrap();
+    assert_eq!(
+        f.bb.lock().unwrap().get("tick".to_string()),
+        Ok(Some(&RtValue::int(5)))
+    );
+
+    let _ = fs::remove_file(&path);
+}
+
+#[test]
+fn checkpoint_skipped_ticks() {
+    struct Gate;
+    impl Impl for Gate {
+        fn tick(&self, _args: RtArgs, ctx: TreeContextRef) -> Tick {
+            if ctx.current_tick().is_multiple_of(2) {
+                Ok(TickResult::running())
+            } else {
+                Ok(TickResult::success())
+            }
+        }
+    }
+
+    let mut fb = fb("actions/checkpoint_gate");
+    fb.register_sync_action("gate", Gate);
+    let mut f = fb.build().unwrap();
+
+    // the checkpoint is visited only on the odd ticks
+    assert!(matches!(f.run_until(Some(4)), Err(RuntimeError::Stopped(_))));
+    let path = test_folder("actions/checkpoint_gate/checkpoint.json");
+    assert_eq!(checkpoint_tick(&path), Some(RtValue::int(3)));
+
+    let _ = fs::remove_file(&path);
+}
+
+fn run_text(text: &str) -> Tick {
+    let mut fb = ForesterBuilder::from_text();
+    fb.text(format!("import \"std::actions\"\n{text}"));
+    let mut f = fb.build().unwrap();
+    f.run()
+}
+
+#[test]
+fn all_true() {
+    let result = run_text(
+        r#"root main sequence {
+            store("a", true)
+            store("b", true)
+            all_true(keys = ["a","b"])
+        }"#,
+    );
+    assert_eq!(result, Ok(TickResult::success()));
+
+    let result = run_text(
+        r#"root main sequence {
+            store("a", true)
+            store("b", false)
+            all_true(keys = ["a","b"])
+        }"#,
+    );
+    assert_eq!(result, Ok(TickResult::failure("not all values are true".to_string())));
+}
+
+#[test]
+fn any_true() {
+    let result = run_text(
+        r#"root main sequence {
+            store("a", false)
+            store("b", true)
+            any_true(keys = ["a","b"])
+        }"#,
+    );
+    assert_eq!(result, Ok(TickResult::success()));
+
+    let result = run_text(
+        r#"root main sequence {
+            store("a", false)
+            store("b", false)
+            any_true(keys = ["a","b"])
+        }"#,
+    );
+    assert_eq!(result, Ok(TickResult::failure("no value is true".to_string())));
+}
+
+#[test]
+fn true_coercion_and_strict() {
+    let result = run_text(
+        r#"root main sequence {
+            store("a", 1)
+            store("b", "yes")
+            all_true(keys = ["a","b"])
+        }"#,
+    );
+    assert_eq!(result, Ok(TickResult::success()));
+
+    let result = run_text(
+        r#"root main sequence {
+            store("a", 1)
+            store("b", "")
+            all_true(keys = ["a","b"])
+        }"#,
+    );
+    assert_eq!(result, Ok(TickResult::failure("not all values are true".to_string())));
+
+    let result = run_text(
+        r#"root main sequence {
+            store("a", true)
+            store("b", 1)
+            any_true(["a","b"], true)
+        }"#,
+    );
+    assert_eq!(
+        result,
+        Ok(TickResult::failure(
+            "io: the value in the key b can not be taken as a bool".to_string()
+        ))
+    );
+}
+
+#[test]
+fn decide() {
+    let mut f = fb("actions/decide").build().unwrap();
+    assert_eq!(f.decision(), None);
+    assert_eq!(f.run(), Ok(TickResult::success()));
+
+    let expected = RtValue::Object(HashMap::from_iter(vec![
+        ("action".to_string(), RtValue::str("flee".to_string())),
+        ("speed".to_string(), RtValue::int(10)),
+    ]));
+    assert_eq!(f.decision(), Some(expected.clone()));
+    assert_eq!(
+        f.bb.lock().unwrap().get("decision".to_string()),
+        Ok(Some(&expected))
+    );
+}
+
+#[test]
+fn no_decision() {
+    let mut f = fb("actions/no_decision").build().unwrap();
+    assert_eq!(f.run(), Ok(TickResult::success()));
+    assert_eq!(f.decision(), None);
+}
+
+#[test]
+fn key_validator() {
+    let build = |key: &str| {
+        let mut fb = fb("actions/key_validator");
+        fb.with_key_validator(|k| {
+            let mut chars = k.chars();
+            chars.next().is_some_and(|c| c.is_ascii_lowercase())
+                && chars.all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_' || c == '.')
+        });
+        let f = fb.build().unwrap();
+        f.bb.lock()
+            .unwrap()
+            .put("key".to_string(), RtValue::str(key.to_string()))
+            .unwrap();
+        f
+    };
+
+    let mut f = build("robot.speed_1");
+    assert_eq!(f.run(), Ok(TickResult::success()));
+    assert_eq!(
+        f.bb.lock().unwrap().get("robot.speed_1".to_string()),
+        Ok(Some(&RtValue::int(1)))
+    );
+
+    let mut f = build("Robot-Speed");
+    assert_eq!(
+        f.run(),
+        Ok(TickResult::failure("the key Robot-Speed is not valid".to_string()))
+    );
+    let mut bb = f.bb.lock().unwrap();
+    assert_eq!(bb.get("Robot-Speed".to_string()), Ok(None));
+    assert_eq!(
+        bb.put("Robot-Speed".to_string(), RtValue::int(1)),
+        Err(RuntimeError::BlackBoardError("the key Robot-Speed is not valid".to_string()))
+    );
+}
+
+#[test]
+fn transition() {
+    let run = |event: Option<RtValue>| {
+        let mut f = fb("actions/transition").build().unwrap();
+        if let Some(event) = event {
+            f.bb.lock().unwrap().put("event".to_string(), event).unwrap();
+        }
+        let result = f.run();
+        let state = f.bb.lock().unwrap().get("door".to_string()).unwrap().cloned();
+        (result, state)
+    };
+    let closed = Some(RtValue::str("closed".to_string()));
+
+    assert_eq!(
+        run(Some(RtValue::str("open".to_string()))),
+        (Ok(TickResult::success()), Some(RtValue::str("opened".to_string())))
+    );
+    assert_eq!(
+        run(Some(RtValue::str("close".to_string()))),
+        (
+            Ok(TickResult::failure("the transition from closed on close is not defined".to_string())),
+            closed.clone()
+        )
+    );
+    assert_eq!(
+        run(None),
+        (Ok(TickResult::failure("io: the key event is absent".to_string())), closed.clone())
+    );
+    assert_eq!(
+        run(Some(RtValue::int(1))),
+        (
+            Ok(TickResult::failure("io: the key event is expected to be a string".to_string())),
+            closed
+        )
+    );
+}
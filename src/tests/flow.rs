@@ -1,10 +1,11 @@
+use std::collections::HashMap;
 use std::net::Incoming;
 use crate::runtime::action::builtin::data::GenerateData;
 use crate::runtime::action::builtin::ReturnResult;
 use crate::runtime::action::{Action, Impl, Tick};
 use crate::runtime::args::{RtArgs, RtValue};
 use crate::runtime::context::{TreeContext, TreeContextRef};
-use crate::runtime::TickResult;
+use crate::runtime::{RuntimeError, TickResult};
 use crate::tests::{fb, test_folder, turn_on_logs};
 use crate::visualizer::Visualizer;
 
@@ -297,4 +298,87 @@ fn parallel_simple_w_retry() {
     assert_eq!(t1, Some(3));
     let t2 = bb.get("t2".to_string()).ok().flatten().unwrap().clone().as_int();
     assert_eq!(t2, Some(3));
-}
\ No newline at end of file
+}
+
+fn run_range_switch(folder: &str, hp: i64) -> (Tick, Option<String>) {
+    let fb = fb(folder);
+    let mut f = fb.build().unwrap();
+    f.bb.lock()
+        .unwrap()
+        .put("hp".to_string(), RtValue::int(hp))
+        .unwrap();
+
+    let result = f.run();
+    let tier =
+        f.bb.lock()
+            .unwrap()
+            .get("tier".to_string())
+            .ok()
+            .flatten()
+            .and_then(|v| v.clone().as_string());
+    (result, tier)
+}
+
+#[test]
+fn range_switch_tiers() {
+    let tiers = "flow/range_switch/tiers";
+
+    assert_eq!(run_range_switch(tiers, 10), (Ok(TickResult::success()), Some("flee".to_string())));
+    assert_eq!(run_range_switch(tiers, 25), (Ok(TickResult::success()), Some("cautious".to_string())));
+    assert_eq!(run_range_switch(tiers, 74), (Ok(TickResult::success()), Some("cautious".to_string())));
+    assert_eq!(run_range_switch(tiers, 75), (Ok(TickResult::success()), Some("aggressive".to_string())));
+}
+
+#[test]
+fn range_switch_overlap() {
+    let overlap = "flow/range_switch/overlap";
+
+    assert_eq!(run_range_switch(overlap, 20), (Ok(TickResult::success()), Some("low".to_string())));
+    assert_eq!(run_range_switch(overlap, 60), (Ok(TickResult::success()), Some("middle".to_string())));
+}
+
+#[test]
+fn range_switch_no_default() {
+    let no_default = "flow/range_switch/no_default";
+
+    assert_eq!(run_range_switch(no_default, 30), (Ok(TickResult::success()), Some("high".to_string())));
+    assert_eq!(
+        run_range_switch(no_default, 100),
+        (Ok(TickResult::failure("no range matches the key".to_string())), None)
+    );
+}
+
+#[test]
+fn range_switch_ranges_ptr() {
+    let mut f = fb("flow/range_switch/ranges_ptr").build().unwrap();
+    {
+        let mut bb = f.bb.lock().unwrap();
+        bb.put("hp".to_string(), RtValue::int(50)).unwrap();
+        bb.put(
+            "ranges".to_string(),
+            RtValue::Array(vec![
+                RtValue::Object(HashMap::from_iter(vec![("lt".to_string(), RtValue::int(25))])),
+                RtValue::Object(HashMap::from_iter(vec![("lt".to_string(), RtValue::int(75))])),
+            ]),
+        )
+        .unwrap();
+    }
+
+    assert_eq!(f.run(), Ok(TickResult::success()));
+    let tier = f.bb.lock().unwrap().get("tier".to_string()).unwrap().cloned();
+    assert_eq!(tier, Some(RtValue::str("cautious".to_string())));
+}
+
+#[test]
+fn range_switch_mismatch() {
+    let (result, tier) = run_range_switch("flow/range_switch/mismatch", 10);
+    assert!(matches!(result, Err(RuntimeError::WrongArgument(_))));
+    assert_eq!(tier, None);
+}
+
+#[test]
+fn range_switch_unknown_bound() {
+    let (result, tier) = run_range_switch("flow/range_switch/unknown_bound", 10);
+    assert!(matches!(result, Err(RuntimeError::WrongArgument(_))));
+    assert_eq!(tier, None);
+}
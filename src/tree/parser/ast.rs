@@ -22,6 +22,7 @@ pub enum TreeType {
     RSequence,
     Fallback,
     RFallback,
+    RangeSwitch,
     // decorators
     Inverter,
     ForceSuccess,
@@ -70,5 +70,6 @@ fn flow_color(tpe: &FlowType) -> Attribute {
         FlowType::RSequence => NodeAttributes::color(color_name::darkred),
         FlowType::Fallback => NodeAttributes::color(color_name::blue),
         FlowType::RFallback => NodeAttributes::color(color_name::blue),
+        FlowType::RangeSwitch => NodeAttributes::color(color_name::darkgreen),
     }
 }
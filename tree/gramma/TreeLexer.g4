@@ -10,6 +10,8 @@ RSEQUENCE : 'r_sequence';
 FALLBACK: 'fallback';
 RFALLBACK : 'r_fallback';
 
+RANGE_SWITCH : 'range_switch';
+
 ARRAY_T: 'array';
 NUM_T: 'num';
 OBJECT_T: 'object';
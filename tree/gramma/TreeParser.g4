@@ -83,6 +83,7 @@ tree_type
     | RSEQUENCE
     | FALLBACK
     | RFALLBACK
+    | RANGE_SWITCH
     | id          // ambigulty
     ;
 